@@ -1,6 +1,75 @@
 use wasm_bindgen::prelude::*;
 use sha2::{Sha256, Digest};
 use serde::{Serialize, Deserialize};
+use base64::Engine;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use blst::min_pk::{
+    AggregatePublicKey, AggregateSignature, PublicKey as BlsPublicKey, SecretKey as BlsSecretKey,
+    Signature as BlsSignature,
+};
+use blst::BLST_ERROR;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A single decoded EVM instruction from a linear-sweep disassembly.
+#[derive(Serialize, Deserialize)]
+pub struct Instruction {
+    pub pc: usize,
+    pub opcode: u8,
+    pub operand: Vec<u8>,
+}
+
+/// Result of a linear-sweep disassembly pass over `BytecodeAnalyzer::bytecode`.
+#[derive(Serialize, Deserialize)]
+pub struct Disassembly {
+    pub instructions: Vec<Instruction>,
+    pub jumpdests: Vec<usize>,
+}
+
+/// A maximal run of instructions with a single entry point and, at most, one exit terminator
+#[derive(Serialize, Deserialize)]
+pub struct BasicBlock {
+    pub start: usize,
+    pub end: usize,
+    pub terminator: Option<u8>,
+}
+
+/// A resolved control-flow edge between two basic blocks, identified by their start `pc`
+#[derive(Serialize, Deserialize)]
+pub struct CfgEdge {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Static control-flow graph over a bytecode's basic blocks
+#[derive(Serialize, Deserialize)]
+pub struct ControlFlowGraph {
+    pub blocks: Vec<BasicBlock>,
+    pub edges: Vec<CfgEdge>,
+    pub unreachable_blocks: Vec<usize>,
+    pub dynamic_jump_count: usize,
+    pub invalid_jump_count: usize,
+}
+
+const PUSH1: u8 = 0x60;
+const PUSH32: u8 = 0x7f;
+const STOP: u8 = 0x00;
+const JUMP: u8 = 0x56;
+const JUMPI: u8 = 0x57;
+const JUMPDEST: u8 = 0x5b;
+const RETURN: u8 = 0xf3;
+const DELEGATECALL: u8 = 0xf4;
+const INVALID: u8 = 0xfe;
+const REVERT: u8 = 0xfd;
+const SELFDESTRUCT: u8 = 0xff;
+
+/// True for opcodes that end a basic block: they either halt execution or
+/// redirect control flow away from the next sequential instruction.
+fn is_terminator(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        STOP | JUMP | JUMPI | RETURN | REVERT | INVALID | SELFDESTRUCT
+    )
+}
 
 #[wasm_bindgen]
 pub struct BytecodeAnalyzer {
@@ -28,27 +97,45 @@ impl BytecodeAnalyzer {
         self.bytecode.len()
     }
 
-    /// Extract EVM opcodes
+    /// Extract EVM opcodes (excludes PUSH immediate/operand bytes)
     pub fn extract_opcodes(&self) -> Vec<u8> {
-        self.bytecode.clone()
+        self.disassemble_internal()
+            .instructions
+            .into_iter()
+            .map(|instr| instr.opcode)
+            .collect()
+    }
+
+    /// Disassemble the bytecode into a structured instruction stream, as JSON
+    pub fn disassemble(&self) -> String {
+        serde_json::to_string(&self.disassemble_internal()).unwrap_or_default()
     }
 
-    /// Detect potential vulnerabilities (basic pattern matching)
+    /// Detect potential vulnerabilities (pattern matching over real opcodes only)
     pub fn detect_patterns(&self) -> String {
         let mut patterns = Vec::new();
+        let disassembly = self.disassemble_internal();
 
-        // Check for selfdestruct opcode (0xff)
-        if self.bytecode.contains(&0xff) {
+        // Check for selfdestruct opcode (0xff) as a genuine instruction, not PUSH data
+        if disassembly
+            .instructions
+            .iter()
+            .any(|instr| instr.opcode == SELFDESTRUCT)
+        {
             patterns.push("selfdestruct_present");
         }
 
-        // Check for delegatecall opcode (0xf4)
-        if self.bytecode.contains(&0xf4) {
+        // Check for delegatecall opcode (0xf4) as a genuine instruction, not PUSH data
+        if disassembly
+            .instructions
+            .iter()
+            .any(|instr| instr.opcode == DELEGATECALL)
+        {
             patterns.push("delegatecall_present");
         }
 
         // Check for fallback function (no-arg function selector)
-        if self.bytecode.len() > 0 {
+        if !self.bytecode.is_empty() {
             patterns.push("has_runtime_code");
         }
 
@@ -78,6 +165,260 @@ impl BytecodeAnalyzer {
 
         entropy
     }
+
+    /// Merkle root over 32-byte bytecode chunks (last chunk zero-padded), as hex
+    pub fn merkle_root(&self) -> String {
+        let levels = self.merkle_levels();
+        hex::encode(levels.last().unwrap()[0])
+    }
+
+    /// Build an inclusion proof for the leaf at `leaf_index`, as a JSON array of
+    /// `{sibling, is_left}` steps ordered from the leaf upward. Returns an empty
+    /// string if `leaf_index` is out of range rather than panicking.
+    pub fn merkle_proof(&self, leaf_index: usize) -> String {
+        let levels = self.merkle_levels();
+        if leaf_index >= levels[0].len() {
+            return String::new();
+        }
+
+        let mut idx = leaf_index;
+        let mut branch = Vec::new();
+        for level in &levels[..levels.len() - 1] {
+            let is_left = idx % 2 == 1;
+            let sibling_idx = if is_left {
+                idx - 1
+            } else if idx + 1 < level.len() {
+                idx + 1
+            } else {
+                idx
+            };
+            branch.push(MerkleStep {
+                sibling: hex::encode(level[sibling_idx]),
+                is_left,
+            });
+            idx /= 2;
+        }
+
+        serde_json::to_string(&branch).unwrap_or_default()
+    }
+
+    /// Build the static control-flow graph (basic blocks, edges, unreachable blocks,
+    /// and a count of jumps whose target could not be statically resolved), as JSON
+    pub fn control_flow_graph(&self) -> String {
+        serde_json::to_string(&self.build_cfg()).unwrap_or_default()
+    }
+}
+
+impl BytecodeAnalyzer {
+    /// Split the bytecode into fixed-size 32-byte leaves, zero-padding the last one
+    fn merkle_leaves(&self) -> Vec<[u8; 32]> {
+        const LEAF_SIZE: usize = 32;
+        if self.bytecode.is_empty() {
+            return vec![[0u8; LEAF_SIZE]];
+        }
+
+        self.bytecode
+            .chunks(LEAF_SIZE)
+            .map(|chunk| {
+                let mut leaf = [0u8; LEAF_SIZE];
+                leaf[..chunk.len()].copy_from_slice(chunk);
+                leaf
+            })
+            .collect()
+    }
+
+    /// Build every level of the Merkle tree, from leaf hashes up to the single root,
+    /// duplicating the last node of a level when its length is odd
+    fn merkle_levels(&self) -> Vec<Vec<[u8; 32]>> {
+        let mut level: Vec<[u8; 32]> = self
+            .merkle_leaves()
+            .iter()
+            .map(|leaf| sha256_array(leaf))
+            .collect();
+        let mut levels = vec![level.clone()];
+
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity(level.len().div_ceil(2));
+            for pair in level.chunks(2) {
+                let left = pair[0];
+                let right = if pair.len() == 2 { pair[1] } else { left };
+                let mut hasher = Sha256::new();
+                hasher.update(left);
+                hasher.update(right);
+                let mut hash = [0u8; 32];
+                hash.copy_from_slice(&hasher.finalize());
+                next.push(hash);
+            }
+            levels.push(next.clone());
+            level = next;
+        }
+
+        levels
+    }
+}
+
+impl BytecodeAnalyzer {
+    /// Linear-sweep disassembly: walks the bytecode opcode-by-opcode, treating
+    /// PUSH1..PUSH32 (0x60..=0x7f) immediates as data rather than further opcodes.
+    fn disassemble_internal(&self) -> Disassembly {
+        let mut instructions = Vec::new();
+        let mut jumpdests = Vec::new();
+        let len = self.bytecode.len();
+        let mut pc = 0usize;
+
+        while pc < len {
+            let opcode = self.bytecode[pc];
+            let mut next_pc = pc + 1;
+            let mut operand = Vec::new();
+
+            if (PUSH1..=PUSH32).contains(&opcode) {
+                let operand_len = (opcode - (PUSH1 - 1)) as usize;
+                let end = (pc + 1 + operand_len).min(len);
+                operand = self.bytecode[pc + 1..end].to_vec();
+                next_pc = end;
+            } else if opcode == JUMPDEST {
+                jumpdests.push(pc);
+            }
+
+            instructions.push(Instruction { pc, opcode, operand });
+            pc = next_pc;
+        }
+
+        Disassembly { instructions, jumpdests }
+    }
+}
+
+impl BytecodeAnalyzer {
+    /// Build the static control-flow graph over the bytecode's basic blocks.
+    ///
+    /// Blocks start at pc 0, every JUMPDEST, and the fall-through instruction after a
+    /// JUMPI; they end at the first terminator opcode encountered (see `is_terminator`)
+    /// or, if none occurs first, where the next block begins. Static jump targets are
+    /// resolved from the `PUSHn <target>; JUMP/JUMPI` idiom; anything else is a dynamic
+    /// (unresolved) jump, and a resolved target that doesn't land on a JUMPDEST is an
+    /// invalid jump — neither contributes a graph edge.
+    fn build_cfg(&self) -> ControlFlowGraph {
+        let disassembly = self.disassemble_internal();
+        let instructions = &disassembly.instructions;
+        let jumpdest_set: HashSet<usize> = disassembly.jumpdests.iter().copied().collect();
+        let pc_index: HashMap<usize, usize> = instructions
+            .iter()
+            .enumerate()
+            .map(|(i, instr)| (instr.pc, i))
+            .collect();
+
+        let mut block_starts: BTreeSet<usize> = BTreeSet::new();
+        block_starts.insert(0);
+        block_starts.extend(disassembly.jumpdests.iter().copied());
+        for (i, instr) in instructions.iter().enumerate() {
+            if instr.opcode == JUMPI {
+                if let Some(next) = instructions.get(i + 1) {
+                    block_starts.insert(next.pc);
+                }
+            }
+        }
+        let starts: Vec<usize> = block_starts.into_iter().collect();
+
+        let mut blocks = Vec::with_capacity(starts.len());
+        let mut edges = Vec::new();
+        let mut dynamic_jump_count = 0usize;
+        let mut invalid_jump_count = 0usize;
+
+        for (si, &start_pc) in starts.iter().enumerate() {
+            let Some(&start_idx) = pc_index.get(&start_pc) else {
+                continue;
+            };
+            let next_block_start = starts.get(si + 1).copied();
+
+            let mut idx = start_idx;
+            let mut terminator = None;
+            loop {
+                if is_terminator(instructions[idx].opcode) {
+                    terminator = Some(instructions[idx].opcode);
+                    break;
+                }
+                match instructions.get(idx + 1) {
+                    Some(next_instr) if Some(next_instr.pc) != next_block_start => idx += 1,
+                    _ => break,
+                }
+            }
+
+            let end_pc = instructions[idx].pc;
+            let fallthrough_pc = instructions.get(idx + 1).map(|instr| instr.pc);
+            blocks.push(BasicBlock { start: start_pc, end: end_pc, terminator });
+
+            match terminator {
+                Some(JUMP) => match resolve_jump_target(instructions, idx) {
+                    Some(target) if jumpdest_set.contains(&target) => {
+                        edges.push(CfgEdge { from: start_pc, to: target });
+                    }
+                    Some(_) => invalid_jump_count += 1, // resolved but not a JUMPDEST
+                    None => dynamic_jump_count += 1,
+                },
+                Some(JUMPI) => {
+                    match resolve_jump_target(instructions, idx) {
+                        Some(target) if jumpdest_set.contains(&target) => {
+                            edges.push(CfgEdge { from: start_pc, to: target });
+                        }
+                        Some(_) => invalid_jump_count += 1,
+                        None => dynamic_jump_count += 1,
+                    }
+                    if let Some(to) = fallthrough_pc {
+                        edges.push(CfgEdge { from: start_pc, to });
+                    }
+                }
+                Some(_) => {} // STOP/RETURN/REVERT/INVALID/SELFDESTRUCT: no successors
+                None => {
+                    if let Some(to) = fallthrough_pc {
+                        edges.push(CfgEdge { from: start_pc, to });
+                    }
+                }
+            }
+        }
+
+        let mut reachable: HashSet<usize> = HashSet::new();
+        let mut stack = vec![0usize];
+        while let Some(pc) = stack.pop() {
+            if !reachable.insert(pc) {
+                continue;
+            }
+            for edge in &edges {
+                if edge.from == pc {
+                    stack.push(edge.to);
+                }
+            }
+        }
+
+        let unreachable_blocks = starts
+            .iter()
+            .filter(|pc| !reachable.contains(pc))
+            .copied()
+            .collect();
+
+        ControlFlowGraph {
+            blocks,
+            edges,
+            unreachable_blocks,
+            dynamic_jump_count,
+            invalid_jump_count,
+        }
+    }
+}
+
+/// Resolve the static jump target of the JUMP/JUMPI instruction at `instructions[idx]`
+/// from the immediately preceding `PUSHn <target>` idiom. Returns `None` when the jump
+/// isn't preceded by a PUSH (a dynamic/unresolvable jump).
+fn resolve_jump_target(instructions: &[Instruction], idx: usize) -> Option<usize> {
+    let prev = instructions.get(idx.checked_sub(1)?)?;
+    if !(PUSH1..=PUSH32).contains(&prev.opcode) {
+        return None;
+    }
+
+    let mut value: u128 = 0;
+    for &byte in &prev.operand {
+        value = (value << 8) | byte as u128;
+    }
+    Some(usize::try_from(value).unwrap_or(usize::MAX))
 }
 
 #[derive(Serialize, Deserialize)]
@@ -85,6 +426,127 @@ pub struct ProofData {
     pub contract_address: String,
     pub proof_hash: String,
     pub timestamp: u64,
+    pub difficulty: u32,
+    pub nonce: u64,
+}
+
+const JWT_HEADER: &str = "{\"alg\":\"EdDSA\",\"typ\":\"JWT\"}";
+
+/// Claims carried in the payload of a signed proof JWT
+#[derive(Serialize, Deserialize)]
+struct SignedProofClaims {
+    contract_address: String,
+    proof_hash: String,
+    timestamp: u64,
+    iss: String,
+}
+
+/// A minimal OKP/Ed25519 JSON Web Key, as used for signing and verifying proof JWTs
+#[derive(Serialize, Deserialize)]
+struct Jwk {
+    kty: String,
+    crv: String,
+    x: String,
+    #[serde(default)]
+    d: Option<String>,
+}
+
+impl Jwk {
+    fn to_signing_key(&self) -> Option<SigningKey> {
+        if self.kty != "OKP" || self.crv != "Ed25519" {
+            return None;
+        }
+        let seed_bytes = b64url_decode(self.d.as_deref()?)?;
+        let seed: [u8; 32] = seed_bytes.try_into().ok()?;
+        Some(SigningKey::from_bytes(&seed))
+    }
+
+    fn to_verifying_key(&self) -> Option<VerifyingKey> {
+        if self.kty != "OKP" || self.crv != "Ed25519" {
+            return None;
+        }
+        let pub_bytes = b64url_decode(&self.x)?;
+        let pub_bytes: [u8; 32] = pub_bytes.try_into().ok()?;
+        VerifyingKey::from_bytes(&pub_bytes).ok()
+    }
+}
+
+fn b64url_encode(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn b64url_decode(s: &str) -> Option<Vec<u8>> {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(s).ok()
+}
+
+/// One step of a Merkle inclusion proof: a sibling hash and which side it sits on
+#[derive(Serialize, Deserialize)]
+pub struct MerkleStep {
+    pub sibling: String,
+    pub is_left: bool,
+}
+
+/// Verify that `leaf` (the hex-encoded SHA256 hash of a bytecode segment) is included
+/// in the tree committed to by `root`, by folding it up the given `branch` (a JSON
+/// array of `MerkleStep`s as produced by `BytecodeAnalyzer::merkle_proof`).
+#[wasm_bindgen]
+pub fn verify_merkle_proof(root: &str, leaf: &str, index: usize, branch: &str) -> bool {
+    let steps: Vec<MerkleStep> = match serde_json::from_str(branch) {
+        Ok(steps) => steps,
+        Err(_) => return false,
+    };
+    let mut current = match hex::decode(leaf) {
+        Ok(bytes) => bytes,
+        Err(_) => return false,
+    };
+
+    let mut idx = index;
+    for step in &steps {
+        // The step's side must agree with the leaf's own position in the tree
+        if step.is_left != (idx % 2 == 1) {
+            return false;
+        }
+        let sibling = match hex::decode(&step.sibling) {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+
+        let mut hasher = Sha256::new();
+        if step.is_left {
+            hasher.update(&sibling);
+            hasher.update(&current);
+        } else {
+            hasher.update(&current);
+            hasher.update(&sibling);
+        }
+        current = hasher.finalize().to_vec();
+        idx /= 2;
+    }
+
+    hex::encode(current) == root
+}
+
+/// SHA256 a byte slice into a fixed-size 32-byte array
+fn sha256_array(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&hasher.finalize());
+    arr
+}
+
+/// Count the number of leading zero bits in a digest, MSB-first, stopping at the first set bit
+fn leading_zero_bits(digest: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in digest {
+        if *byte == 0 {
+            bits += 8;
+        } else {
+            bits += byte.leading_zeros();
+            break;
+        }
+    }
+    bits
 }
 
 #[wasm_bindgen]
@@ -101,37 +563,253 @@ impl ProofGenerator {
         ProofGenerator { challenge }
     }
 
-    /// Generate a proof
+    /// Generate a proof (no proof-of-work required)
     pub fn generate_proof(&self, contract_addr: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(&self.challenge);
-        hasher.update(contract_addr.as_bytes());
-        
-        let proof_hash = hex::encode(hasher.finalize());
+        self.generate_proof_with_difficulty(contract_addr, 0)
+    }
+
+    /// Generate a proof that additionally requires `difficulty` leading zero bits of
+    /// proof-of-work, useful for rate-limiting on-chain attestation submissions
+    pub fn generate_proof_with_difficulty(&self, contract_addr: &str, difficulty: u32) -> String {
+        let mut nonce: u64 = 0;
+        let proof_hash = loop {
+            let mut hasher = Sha256::new();
+            hasher.update(&self.challenge);
+            hasher.update(contract_addr.as_bytes());
+            hasher.update(nonce.to_le_bytes());
+            let digest = hasher.finalize();
+
+            if leading_zero_bits(&digest) >= difficulty {
+                break hex::encode(digest);
+            }
+            nonce += 1;
+        };
 
         let proof = ProofData {
             contract_address: contract_addr.to_string(),
             proof_hash,
             timestamp: timestamp(),
+            difficulty,
+            nonce,
         };
 
         serde_json::to_string(&proof).unwrap_or_default()
     }
 
-    /// Verify a proof (basic check)
+    /// Verify a proof, including its proof-of-work difficulty claim
     pub fn verify_proof(&self, proof_json: &str) -> bool {
         if let Ok(proof) = serde_json::from_str::<ProofData>(proof_json) {
-            // Verify proof hash matches challenge
             let mut hasher = Sha256::new();
             hasher.update(&self.challenge);
             hasher.update(proof.contract_address.as_bytes());
-            
-            let expected_hash = hex::encode(hasher.finalize());
-            proof.proof_hash == expected_hash
+            hasher.update(proof.nonce.to_le_bytes());
+            let digest = hasher.finalize();
+
+            let expected_hash = hex::encode(digest);
+            proof.proof_hash == expected_hash && leading_zero_bits(&digest) >= proof.difficulty
         } else {
             false
         }
     }
+
+    /// Generate a proof and issue it as a compact, tamper-evident JWS/JWT signed with an
+    /// Ed25519 key supplied as an OKP JWK (`{"kty":"OKP","crv":"Ed25519","x":...,"d":...}`)
+    pub fn generate_signed_proof(&self, priv_key_jwk: &str, contract_addr: &str) -> String {
+        let jwk: Jwk = match serde_json::from_str(priv_key_jwk) {
+            Ok(jwk) => jwk,
+            Err(_) => return String::new(),
+        };
+        let signing_key = match jwk.to_signing_key() {
+            Some(key) => key,
+            None => return String::new(),
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(&self.challenge);
+        hasher.update(contract_addr.as_bytes());
+        let proof_hash = hex::encode(hasher.finalize());
+
+        let claims = SignedProofClaims {
+            contract_address: contract_addr.to_string(),
+            proof_hash,
+            timestamp: timestamp(),
+            iss: jwk.x.clone(),
+        };
+
+        let header_b64 = b64url_encode(JWT_HEADER.as_bytes());
+        let payload_b64 = match serde_json::to_vec(&claims) {
+            Ok(bytes) => b64url_encode(&bytes),
+            Err(_) => return String::new(),
+        };
+
+        let signing_input = format!("{}.{}", header_b64, payload_b64);
+        let signature = signing_key.sign(signing_input.as_bytes());
+        let signature_b64 = b64url_encode(&signature.to_bytes());
+
+        format!("{}.{}", signing_input, signature_b64)
+    }
+
+    /// Verify a JWT produced by `generate_signed_proof` against the issuer's public OKP JWK
+    pub fn verify_signed_proof(pub_key_jwk: &str, jwt: &str) -> bool {
+        let jwk: Jwk = match serde_json::from_str(pub_key_jwk) {
+            Ok(jwk) => jwk,
+            Err(_) => return false,
+        };
+        let verifying_key = match jwk.to_verifying_key() {
+            Some(key) => key,
+            None => return false,
+        };
+
+        let parts: Vec<&str> = jwt.split('.').collect();
+        if parts.len() != 3 {
+            return false;
+        }
+        let (header_b64, payload_b64, signature_b64) = (parts[0], parts[1], parts[2]);
+
+        let header_bytes = match b64url_decode(header_b64) {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+        if header_bytes != JWT_HEADER.as_bytes() {
+            return false;
+        }
+
+        let payload_bytes = match b64url_decode(payload_b64) {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+        let claims: SignedProofClaims = match serde_json::from_slice(&payload_bytes) {
+            Ok(claims) => claims,
+            Err(_) => return false,
+        };
+        if claims.iss != jwk.x {
+            return false;
+        }
+
+        let signature_bytes = match b64url_decode(signature_b64) {
+            Some(bytes) if bytes.len() == 64 => bytes,
+            _ => return false,
+        };
+        let signature = match Signature::from_slice(&signature_bytes) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        let signing_input = format!("{}.{}", header_b64, payload_b64);
+        verifying_key
+            .verify(signing_input.as_bytes(), &signature)
+            .is_ok()
+    }
+}
+
+/// Domain-separation tag for BLS attestation signatures, so they cannot be replayed
+/// against a different signing context (e.g. a different message/scheme binding)
+const BLS_DST: &[u8] = b"VIGILUM_BLS_ATTESTATION_DST_V1_BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// BLS12-381 (min-pk) multi-auditor attestation signing, aggregation, and verification.
+/// Several independent auditors can co-sign the same verdict and collapse their
+/// signatures into one compact aggregate checkable with a single pairing.
+#[wasm_bindgen]
+pub struct BlsAttestation;
+
+#[wasm_bindgen]
+impl BlsAttestation {
+    /// Sign a verdict's message hash with an auditor's BLS12-381 secret key
+    pub fn sign_attestation(secret_key_hex: &str, message_hash_hex: &str) -> String {
+        let sk_bytes = match hex::decode(secret_key_hex) {
+            Ok(bytes) => bytes,
+            Err(_) => return String::new(),
+        };
+        let msg = match hex::decode(message_hash_hex) {
+            Ok(bytes) => bytes,
+            Err(_) => return String::new(),
+        };
+        let sk = match BlsSecretKey::from_bytes(&sk_bytes) {
+            Ok(sk) => sk,
+            Err(_) => return String::new(),
+        };
+
+        let sig = sk.sign(&msg, BLS_DST, &[]);
+        hex::encode(sig.to_bytes())
+    }
+
+    /// Combine multiple auditors' signatures over the same message into one aggregate
+    pub fn aggregate_signatures(signatures_hex: Vec<String>) -> String {
+        if signatures_hex.is_empty() {
+            return String::new();
+        }
+
+        let mut sigs = Vec::with_capacity(signatures_hex.len());
+        for sig_hex in &signatures_hex {
+            let bytes = match hex::decode(sig_hex) {
+                Ok(bytes) => bytes,
+                Err(_) => return String::new(),
+            };
+            match BlsSignature::from_bytes(&bytes) {
+                Ok(sig) => sigs.push(sig),
+                Err(_) => return String::new(),
+            }
+        }
+
+        let refs: Vec<&BlsSignature> = sigs.iter().collect();
+        match AggregateSignature::aggregate(&refs, true) {
+            Ok(agg) => hex::encode(agg.to_signature().to_bytes()),
+            Err(_) => String::new(),
+        }
+    }
+
+    /// Combine multiple auditors' public keys into one aggregate public key
+    pub fn aggregate_public_keys(public_keys_hex: Vec<String>) -> String {
+        if public_keys_hex.is_empty() {
+            return String::new();
+        }
+
+        let mut pks = Vec::with_capacity(public_keys_hex.len());
+        for pk_hex in &public_keys_hex {
+            let bytes = match hex::decode(pk_hex) {
+                Ok(bytes) => bytes,
+                Err(_) => return String::new(),
+            };
+            match BlsPublicKey::from_bytes(&bytes) {
+                Ok(pk) => pks.push(pk),
+                Err(_) => return String::new(),
+            }
+        }
+
+        let refs: Vec<&BlsPublicKey> = pks.iter().collect();
+        match AggregatePublicKey::aggregate(&refs, true) {
+            Ok(agg) => hex::encode(agg.to_public_key().to_bytes()),
+            Err(_) => String::new(),
+        }
+    }
+
+    /// Fast-aggregate-verify: all signers attested to the same `message_hash_hex`, so
+    /// verification reduces to one pairing check against the summed public key
+    pub fn verify_aggregate(agg_pubkey_hex: &str, message_hash_hex: &str, agg_sig_hex: &str) -> bool {
+        let pk_bytes = match hex::decode(agg_pubkey_hex) {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+        let msg = match hex::decode(message_hash_hex) {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+        let sig_bytes = match hex::decode(agg_sig_hex) {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+
+        let pk = match BlsPublicKey::from_bytes(&pk_bytes) {
+            Ok(pk) => pk,
+            Err(_) => return false,
+        };
+        let sig = match BlsSignature::from_bytes(&sig_bytes) {
+            Ok(sig) => sig,
+            Err(_) => return false,
+        };
+
+        sig.verify(true, &msg, BLS_DST, &[], &pk, true) == BLST_ERROR::BLST_SUCCESS
+    }
 }
 
 /// Get current timestamp in seconds
@@ -161,6 +839,85 @@ mod tests {
         assert!(!analyzer.hash().is_empty());
     }
 
+    #[test]
+    fn test_detect_patterns_ignores_push_immediates() {
+        // PUSH1 0xff (immediate data, not a real SELFDESTRUCT) followed by STOP
+        let analyzer = BytecodeAnalyzer::new("60ff00");
+        let patterns = analyzer.detect_patterns();
+        assert!(!patterns.contains("selfdestruct_present"));
+
+        // A genuine SELFDESTRUCT opcode after the PUSH1 must still be detected
+        let analyzer = BytecodeAnalyzer::new("60ffff");
+        assert!(analyzer.detect_patterns().contains("selfdestruct_present"));
+    }
+
+    #[test]
+    fn test_disassemble_collects_jumpdests() {
+        // PUSH1 0x05, JUMP, JUMPDEST, STOP
+        let analyzer = BytecodeAnalyzer::new("6005565b00");
+        let disassembly = analyzer.disassemble();
+        assert!(disassembly.contains("\"jumpdests\":[3]"));
+    }
+
+    #[test]
+    fn test_merkle_proof_roundtrip() {
+        // 100 bytes spans 4 leaves (32 + 32 + 32 + 4 zero-padded)
+        let bytecode_hex = "60".repeat(100);
+        let analyzer = BytecodeAnalyzer::new(&bytecode_hex);
+        let root = analyzer.merkle_root();
+
+        for leaf_index in 0..4 {
+            let branch = analyzer.merkle_proof(leaf_index);
+            assert!(!branch.is_empty());
+
+            let leaf_bytes = analyzer.merkle_leaves()[leaf_index];
+            let leaf_hash = hex::encode(sha256_array(&leaf_bytes));
+            assert!(verify_merkle_proof(&root, &leaf_hash, leaf_index, &branch));
+        }
+
+        // Out-of-range index must fail, not panic
+        assert_eq!(analyzer.merkle_proof(4), "");
+
+        // A mismatched leaf hash must not verify
+        let branch = analyzer.merkle_proof(0);
+        assert!(!verify_merkle_proof(&root, &"00".repeat(32), 0, &branch));
+    }
+
+    #[test]
+    fn test_control_flow_graph_resolves_static_jump() {
+        // PUSH1 0x05; JUMP; STOP; STOP; JUMPDEST; STOP
+        let analyzer = BytecodeAnalyzer::new("60055600005b00");
+        let cfg: ControlFlowGraph = serde_json::from_str(&analyzer.control_flow_graph()).unwrap();
+
+        assert_eq!(cfg.blocks.len(), 2);
+        assert_eq!(cfg.edges.len(), 1);
+        assert_eq!(cfg.edges[0].from, 0);
+        assert_eq!(cfg.edges[0].to, 5);
+        assert!(cfg.unreachable_blocks.is_empty());
+        assert_eq!(cfg.dynamic_jump_count, 0);
+    }
+
+    #[test]
+    fn test_control_flow_graph_flags_unreachable_and_dynamic_jump() {
+        // STOP; JUMPDEST (unreachable); JUMP (dynamic, not preceded by PUSH); STOP
+        let analyzer = BytecodeAnalyzer::new("005b5600");
+        let cfg: ControlFlowGraph = serde_json::from_str(&analyzer.control_flow_graph()).unwrap();
+
+        assert_eq!(cfg.unreachable_blocks, vec![1]);
+        assert_eq!(cfg.dynamic_jump_count, 1);
+    }
+
+    #[test]
+    fn test_control_flow_graph_flags_invalid_jump_target() {
+        // PUSH1 0x03; JUMP (target pc 3 is not a JUMPDEST); STOP
+        let analyzer = BytecodeAnalyzer::new("60035600");
+        let cfg: ControlFlowGraph = serde_json::from_str(&analyzer.control_flow_graph()).unwrap();
+
+        assert!(cfg.edges.is_empty());
+        assert_eq!(cfg.dynamic_jump_count, 0);
+        assert_eq!(cfg.invalid_jump_count, 1);
+    }
+
     #[test]
     fn test_proof_generation() {
         let generator = ProofGenerator::new("deadbeef");
@@ -168,4 +925,70 @@ mod tests {
         assert!(!proof.is_empty());
         assert!(generator.verify_proof(&proof));
     }
+
+    #[test]
+    fn test_proof_of_work_difficulty() {
+        let generator = ProofGenerator::new("deadbeef");
+        let proof = generator.generate_proof_with_difficulty("0x1234", 8);
+        assert!(generator.verify_proof(&proof));
+
+        let data: ProofData = serde_json::from_str(&proof).unwrap();
+        assert_eq!(data.difficulty, 8);
+        assert!(data.proof_hash.starts_with("00"));
+
+        // Tampering with the recorded nonce must invalidate the proof
+        let tampered = proof.replace(&format!("\"nonce\":{}", data.nonce), "\"nonce\":0");
+        assert!(!generator.verify_proof(&tampered));
+    }
+
+    #[test]
+    fn test_signed_proof_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let verifying_key = signing_key.verifying_key();
+        let priv_x = b64url_encode(verifying_key.as_bytes());
+        let priv_d = b64url_encode(&signing_key.to_bytes());
+
+        let priv_jwk = format!(
+            "{{\"kty\":\"OKP\",\"crv\":\"Ed25519\",\"x\":\"{}\",\"d\":\"{}\"}}",
+            priv_x, priv_d
+        );
+        let pub_jwk = format!("{{\"kty\":\"OKP\",\"crv\":\"Ed25519\",\"x\":\"{}\"}}", priv_x);
+
+        let generator = ProofGenerator::new("deadbeef");
+        let jwt = generator.generate_signed_proof(&priv_jwk, "0x1234");
+        assert_eq!(jwt.matches('.').count(), 2);
+        assert!(ProofGenerator::verify_signed_proof(&pub_jwk, &jwt));
+
+        // Tampering with the payload must make verification fail
+        let mut parts: Vec<&str> = jwt.split('.').collect();
+        let tampered_payload = b64url_encode(b"{\"contract_address\":\"0xdead\"}");
+        parts[1] = &tampered_payload;
+        let tampered_jwt = parts.join(".");
+        assert!(!ProofGenerator::verify_signed_proof(&pub_jwk, &tampered_jwt));
+    }
+
+    #[test]
+    fn test_bls_aggregate_attestation() {
+        let sk1 = BlsSecretKey::key_gen(&[1u8; 32], &[]).unwrap();
+        let sk2 = BlsSecretKey::key_gen(&[2u8; 32], &[]).unwrap();
+        let sk1_hex = hex::encode(sk1.to_bytes());
+        let sk2_hex = hex::encode(sk2.to_bytes());
+        let pk1_hex = hex::encode(sk1.sk_to_pk().to_bytes());
+        let pk2_hex = hex::encode(sk2.sk_to_pk().to_bytes());
+        let msg_hex = hex::encode([9u8; 32]);
+
+        let sig1 = BlsAttestation::sign_attestation(&sk1_hex, &msg_hex);
+        let sig2 = BlsAttestation::sign_attestation(&sk2_hex, &msg_hex);
+
+        let agg_sig = BlsAttestation::aggregate_signatures(vec![sig1, sig2]);
+        let agg_pk = BlsAttestation::aggregate_public_keys(vec![pk1_hex, pk2_hex]);
+        assert!(BlsAttestation::verify_aggregate(&agg_pk, &msg_hex, &agg_sig));
+
+        // Empty signer sets must be rejected rather than producing a degenerate aggregate
+        assert!(BlsAttestation::aggregate_signatures(vec![]).is_empty());
+        assert!(BlsAttestation::aggregate_public_keys(vec![]).is_empty());
+
+        // Malformed (non-hex) key encodings must surface as false, not panic
+        assert!(!BlsAttestation::verify_aggregate("not-hex", &msg_hex, &agg_sig));
+    }
 }